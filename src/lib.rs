@@ -6,7 +6,8 @@
 //!
 //! **STM32H750 DMA Limitations**: The DMA controller can only access memory regions that are accessible via the AXI bus.
 //! This includes:
-//! - SRAM1/2/3 (0x3000_0000 - 0x3004_0000)
+//! - SRAM1/2/3 (0x3000_0000 - 0x3004_8000), D2 domain, reachable by DMA1/DMA2
+//! - SRAM4 / Backup SRAM (0x3800_0000 - 0x3801_0000, 0x3880_0000 - 0x3880_1000), D3 domain, reachable only by BDMA
 //! - DTCM-RAM (0x2000_0000 - 0x2002_0000)
 //! - ITCM-RAM (0x0000_0000 - 0x0002_0000)
 //!
@@ -14,7 +15,8 @@
 //!
 //! Attempting to access other memory regions will result in a bus error
 //! and cause the microcontroller to enter a Halt state. This crate ensures safety by restricting DMA buffers
-//! to these approved regions only.
+//! to these approved regions only. Not every region is reachable by every DMA controller; see
+//! [`DmaAccessible::REACHABLE_BY`].
 //!
 //! ## Usage
 //!
@@ -33,6 +35,10 @@
 //! see RM0433(datasheet), p. 130, p. 131
 //!
 //! - `Sram1`: SRAM1 region (0x3000_0000 - 0x3002_0000)
+//! - `Sram2`: SRAM2 region (0x3002_0000 - 0x3004_0000)
+//! - `Sram3`: SRAM3 region (0x3004_0000 - 0x3004_8000)
+//! - `Sram4`: SRAM4 region, D3 domain (0x3800_0000 - 0x3801_0000)
+//! - `BackupSram`: Backup SRAM region, D3 domain (0x3880_0000 - 0x3880_1000)
 //! - `Dtcm`: DTCM-RAM region (0x2000_0000 - 0x2001_0000)
 //! - `Itcm`: ITCM-RAM region (0x0000_0000 - 0x0001_0000)
 
@@ -43,10 +49,52 @@ use core::ptr::NonNull;
 
 use grounded::uninit::GroundedArrayCell;
 
+/// Cortex-M7 D-cache line size in bytes. `clean_dcache_by_slice`/`invalidate_dcache_by_slice`
+/// operate on whole cache lines, so buffer start and length must be multiples of this.
+const DCACHE_LINE_SIZE: usize = 32;
+
+/// In debug builds, panics if `addr`/`len_bytes` is not 32-byte cache-line aligned. Shared by
+/// every cache-maintenance call site so the alignment requirement can't drift between them.
+fn assert_cache_line_aligned(addr: usize, len_bytes: usize) {
+    debug_assert_eq!(
+        addr % DCACHE_LINE_SIZE,
+        0,
+        "DMA buffer start address must be 32-byte cache-line aligned"
+    );
+    debug_assert_eq!(
+        len_bytes % DCACHE_LINE_SIZE,
+        0,
+        "DMA buffer length must be a multiple of the 32-byte cache line size"
+    );
+}
+
+/// Which DMA controller class can reach a given region on the STM32H750.
+///
+/// DMA1/DMA2 (and MDMA) operate out of the D2 domain and cannot reach the D3 domain; BDMA lives
+/// in D3 and can only reach SRAM4 and backup SRAM (see RM0433, p. 129-131). Picking a region
+/// whose `REACHABLE_BY` doesn't match the DMA channel being wired up is a configuration error,
+/// not something this crate can check for you at the type level (the channel isn't typed here),
+/// but it's exposed so callers can assert it themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DmaController {
+    /// DMA1 / DMA2, the D2-domain general-purpose DMA controllers.
+    MainDma,
+    /// BDMA, the D3-domain (low-power domain) DMA controller.
+    Bdma,
+}
+
 // Trait representing a DMA-accessible memory region
 pub trait DmaAccessible {
     const START_ADDR: usize;
     const END_ADDR: usize;
+    /// Whether this region is covered by the Cortex-M7 L1 D-cache.
+    ///
+    /// On the STM32H750, AXI/D2 SRAM is cacheable while DTCM/ITCM bypass the cache entirely.
+    /// DMA targeting a cacheable region needs explicit cache maintenance, or the CPU and the DMA
+    /// controller can observe stale data (see RM0433, p. 130-131).
+    const CACHEABLE: bool;
+    /// Which DMA controller class can actually reach this region.
+    const REACHABLE_BY: DmaController;
 }
 
 /// SRAM1 memory region (0x3000_0000 - 0x3002_0000)
@@ -61,19 +109,69 @@ pub struct Dtcm;
 /// This region is accessible via AXI bus and safe for DMA operations.
 pub struct Itcm;
 
+/// SRAM2 memory region, D2 domain (0x3002_0000 - 0x3004_0000)
+/// Reachable by DMA1/DMA2, not by BDMA.
+pub struct Sram2;
+
+/// SRAM3 memory region, D2 domain (0x3004_0000 - 0x3004_8000)
+/// Reachable by DMA1/DMA2, not by BDMA.
+pub struct Sram3;
+
+/// SRAM4 memory region, D3 domain (0x3800_0000 - 0x3801_0000)
+/// Only reachable by BDMA; DMA1/DMA2 cannot cross into the D3 domain.
+pub struct Sram4;
+
+/// Backup SRAM, D3 domain (0x3880_0000 - 0x3880_1000)
+/// Only reachable by BDMA; DMA1/DMA2 cannot cross into the D3 domain.
+pub struct BackupSram;
+
 impl DmaAccessible for Sram1 {
     const START_ADDR: usize = 0x3000_0000; // SRAM1 start address (RM0433, p. 131)
     const END_ADDR: usize = 0x3002_0000; // SRAM1 end address
+    const CACHEABLE: bool = true; // AXI/D2 SRAM is covered by the L1 D-cache
+    const REACHABLE_BY: DmaController = DmaController::MainDma;
 }
 
 impl DmaAccessible for Dtcm {
     const START_ADDR: usize = 0x2000_0000; // DTCM start address (RM0433, p. 131)
     const END_ADDR: usize = 0x2001_0000; // DTCM end address
+    const CACHEABLE: bool = false; // DTCM bypasses the D-cache
+    const REACHABLE_BY: DmaController = DmaController::MainDma;
 }
 
 impl DmaAccessible for Itcm {
     const START_ADDR: usize = 0x0000_0000; // ITCM start address (RM0433, p. 131)
     const END_ADDR: usize = 0x0001_0000; // ITCM end address
+    const CACHEABLE: bool = false; // ITCM bypasses the D-cache
+    const REACHABLE_BY: DmaController = DmaController::MainDma;
+}
+
+impl DmaAccessible for Sram2 {
+    const START_ADDR: usize = 0x3002_0000; // SRAM2 start address (RM0433, p. 131)
+    const END_ADDR: usize = 0x3004_0000; // SRAM2 end address
+    const CACHEABLE: bool = true; // AXI/D2 SRAM is covered by the L1 D-cache
+    const REACHABLE_BY: DmaController = DmaController::MainDma;
+}
+
+impl DmaAccessible for Sram3 {
+    const START_ADDR: usize = 0x3004_0000; // SRAM3 start address (RM0433, p. 131)
+    const END_ADDR: usize = 0x3004_8000; // SRAM3 end address
+    const CACHEABLE: bool = true; // AXI/D2 SRAM is covered by the L1 D-cache
+    const REACHABLE_BY: DmaController = DmaController::MainDma;
+}
+
+impl DmaAccessible for Sram4 {
+    const START_ADDR: usize = 0x3800_0000; // SRAM4 start address, D3 domain (RM0433, p. 131)
+    const END_ADDR: usize = 0x3801_0000; // SRAM4 end address
+    const CACHEABLE: bool = false; // D3 domain SRAM is outside the AXI cache hierarchy
+    const REACHABLE_BY: DmaController = DmaController::Bdma;
+}
+
+impl DmaAccessible for BackupSram {
+    const START_ADDR: usize = 0x3880_0000; // Backup SRAM start address, D3 domain (RM0433, p. 131)
+    const END_ADDR: usize = 0x3880_1000; // Backup SRAM end address
+    const CACHEABLE: bool = false; // D3 domain SRAM is outside the AXI cache hierarchy
+    const REACHABLE_BY: DmaController = DmaController::Bdma;
 }
 
 /// A type-safe wrapper for DMA buffers that ensures the buffer is located in a DMA-accessible memory region.
@@ -94,6 +192,18 @@ pub struct DmaBuffer<T, const LEN: usize, Region> {
 
 impl<T: Copy, const LEN: usize, Region: DmaAccessible> DmaBuffer<T, LEN, Region> {
     pub const LENGTH: usize = LEN;
+
+    /// Compile-time proof that `LEN` elements of `T` fit within `Region`'s address range.
+    /// Referencing this associated const forces it to evaluate at compile time, so a buffer
+    /// that's too large for its region fails to build instead of panicking on device. This
+    /// doesn't remove the need for a runtime check: it only proves the buffer *could* fit
+    /// somewhere in the region, not that the specific address the linker places it at leaves
+    /// enough room, since the buffer isn't required to start at `Region::START_ADDR`.
+    const LEN_FITS: () = assert!(
+        LEN * core::mem::size_of::<T>() <= Region::END_ADDR - Region::START_ADDR,
+        "LEN exceeds the size of the DMA-accessible region"
+    );
+
     /// Safe constructor: only accepts buffers placed in specific regions
     ///
     /// # Panics
@@ -106,7 +216,7 @@ impl<T: Copy, const LEN: usize, Region: DmaAccessible> DmaBuffer<T, LEN, Region>
     /// (e.g., it could be a leaked heap allocation). It is just for rejecting local variable’s simple reference.
     /// For DMA safety, ensure the buffer is placed in a `static` variable.
     /// like:
-    /// ```rust,no-run
+    /// ```rust,no_run
     /// use dma_accessible::{DmaBuffer, Sram1};
     /// use grounded::uninit::GroundedArrayCell;
     ///
@@ -123,13 +233,43 @@ impl<T: Copy, const LEN: usize, Region: DmaAccessible> DmaBuffer<T, LEN, Region>
             core::slice::from_raw_parts_mut(ptr, len)
         };
 
+        Self::from_static_mut(buffer)
+    }
+
+    /// Alternate constructor accepting any `'static mut` slice backing, such as a leaked
+    /// `Box<[T]>` or pool-allocated slice, instead of requiring a `GroundedArrayCell`.
+    ///
+    /// # Panics
+    /// Panics if the buffer is not located within the specified DMA-accessible region, or if its
+    /// length does not match `LEN`.
+    ///
+    /// # Safety
+    /// See [`DmaBuffer::new`]: the `'static` lifetime rejects simple references to locals but
+    /// does not by itself guarantee the memory lives in a `static` variable.
+    ///
+    /// `LEN_FITS` compares a byte count (`LEN * size_of::<T>()`), not an element count, against
+    /// the region's byte range, so a multi-byte `T` that doesn't actually fit is rejected at
+    /// compile time rather than silently under-counted:
+    /// ```compile_fail
+    /// use dma_accessible::{DmaBuffer, BackupSram};
+    ///
+    /// // 2000 u32 elements = 8000 bytes, but BackupSram is only 0x1000 (4096) bytes, so this
+    /// // must fail to compile rather than accept a buffer that overruns the region.
+    /// static mut BUFFER: [u32; 2000] = [0; 2000];
+    /// let _ = DmaBuffer::<u32, 2000, BackupSram>::from_static_mut(unsafe { &mut BUFFER });
+    /// ```
+    pub fn from_static_mut(buffer: &'static mut [T]) -> Self {
+        let () = Self::LEN_FITS;
+        assert_eq!(buffer.len(), LEN, "Buffer length does not match LEN");
         let addr = buffer.as_ptr() as usize;
-        // Address range check at compile-time/runtime
+        // `LEN_FITS` only proves LEN could fit somewhere in the region; the buffer's actual
+        // address is wherever the linker placed it, not necessarily `Region::START_ADDR`, so the
+        // full range still needs checking at runtime.
         assert!(
-            addr >= Region::START_ADDR && (addr + LEN) <= Region::END_ADDR,
+            addr >= Region::START_ADDR
+                && addr + LEN * core::mem::size_of::<T>() <= Region::END_ADDR,
             "Buffer not in DMA-accessible region"
         );
-        assert_eq!(buffer.len(), LEN);
         Self {
             ptr: NonNull::from(buffer).cast(),
             _region: PhantomData,
@@ -166,13 +306,259 @@ impl<T: Copy, const LEN: usize, Region: DmaAccessible> DmaBuffer<T, LEN, Region>
     pub fn as_mut_ptr(&mut self) -> *mut T {
         self.ptr.as_ptr()
     }
+
+    /// Flushes CPU-written data to RAM ahead of a memory-to-peripheral DMA transfer.
+    ///
+    /// When `Region::CACHEABLE` is `false` (e.g. DTCM/ITCM) this is a no-op, since those regions
+    /// are never seen by the D-cache in the first place.
+    ///
+    /// # Panics
+    /// In debug builds, panics if the buffer start or length is not 32-byte cache-line aligned,
+    /// which `clean_dcache_by_slice` requires to avoid cleaning adjacent, unrelated memory.
+    pub fn prepare_for_dma_read(&self) {
+        if Region::CACHEABLE {
+            assert_cache_line_aligned(
+                self.ptr.as_ptr() as usize,
+                LEN * core::mem::size_of::<T>(),
+            );
+            // SAFETY: `clean_dcache_by_slice` only needs `&mut SCB` to serialize cache
+            // maintenance instructions; stealing a handle here is sound because we never hand
+            // out an owned `SCB` elsewhere in this crate.
+            unsafe { cortex_m::Peripherals::steal() }
+                .SCB
+                .clean_dcache_by_slice(self.as_slice());
+        }
+    }
+
+    /// Invalidates the CPU's cached view of the buffer after a peripheral-to-memory DMA
+    /// transfer, so subsequent reads observe the data the DMA controller just wrote.
+    ///
+    /// When `Region::CACHEABLE` is `false` this is a no-op.
+    ///
+    /// # Panics
+    /// In debug builds, panics if the buffer start or length is not 32-byte cache-line aligned.
+    /// This is required by `invalidate_dcache_by_slice`: invalidating a partial cache line would
+    /// discard CPU writes to the unrelated data sharing that line.
+    pub fn finish_dma_write(&mut self) {
+        if Region::CACHEABLE {
+            let ptr = self.ptr.as_ptr() as usize;
+            assert_cache_line_aligned(ptr, LEN * core::mem::size_of::<T>());
+            // SAFETY: the CPU has no outstanding references to this memory while the DMA owns
+            // it, so discarding the stale cache lines here cannot drop unflushed writes. See the
+            // `steal` note in `prepare_for_dma_read` above.
+            unsafe {
+                cortex_m::Peripherals::steal()
+                    .SCB
+                    .invalidate_dcache_by_slice(self.as_mut_slice());
+            }
+        }
+    }
+}
+
+/// Which half of a [`DmaRingBuffer`] is currently owned by the CPU (the other half belongs to
+/// the DMA controller).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ActiveHalf {
+    First,
+    Second,
+}
+
+impl ActiveHalf {
+    fn swap(self) -> Self {
+        match self {
+            ActiveHalf::First => ActiveHalf::Second,
+            ActiveHalf::Second => ActiveHalf::First,
+        }
+    }
+}
+
+/// A double-buffered (ping-pong) DMA buffer for continuous streaming transfers, such as audio or
+/// sensor sampling, where the CPU processes one half while the peripheral fills the other.
+///
+/// This owns a single region-validated backing of `2 * LEN` elements and tracks which half is
+/// currently CPU-owned. Hand the whole backing to embassy's circular DMA transfer via
+/// [`DmaRingBuffer::dma_ptr_len`]; once a half-transfer-complete or transfer-complete interrupt
+/// fires, call [`DmaRingBuffer::commit_and_swap`] to flip ownership, then use
+/// [`DmaRingBuffer::cpu_half`]/[`DmaRingBuffer::cpu_half_mut`] to reach the half that's now safe
+/// for the CPU to read/write.
+///
+/// # Type Parameters
+/// - `T`: The type of elements in the buffer
+/// - `LEN`: The length of *each half* (the backing is `2 * LEN` elements)
+/// - `Region`: A type implementing `DmaAccessible` that specifies the memory region
+///
+/// # Safety
+/// The buffer address range is validated at construction time to ensure the whole `2 * LEN`
+/// backing falls within the specified region.
+pub struct DmaRingBuffer<T, const LEN: usize, Region> {
+    ptr: NonNull<T>,
+    cpu_half: ActiveHalf,
+    _region: PhantomData<Region>,
+}
+
+impl<T: Copy, const LEN: usize, Region: DmaAccessible> DmaRingBuffer<T, LEN, Region> {
+    /// Length of a single half. The backing allocation is `2 * HALF_LENGTH` elements.
+    pub const HALF_LENGTH: usize = LEN;
+
+    /// Compile-time proof that the `2 * LEN` backing, in bytes, fits within `Region`'s address
+    /// range. See [`DmaBuffer::LEN_FITS`] for why a runtime range check is still required on top
+    /// of this.
+    const LEN_FITS: () = assert!(
+        LEN * 2 * core::mem::size_of::<T>() <= Region::END_ADDR - Region::START_ADDR,
+        "2 * LEN exceeds the size of the DMA-accessible region"
+    );
+
+    /// Constructs a ring buffer from a `'static mut` slice of exactly `2 * LEN` elements.
+    ///
+    /// # Panics
+    /// Panics if `buffer.len() != 2 * LEN`, or if the buffer is not located within the
+    /// specified DMA-accessible region.
+    ///
+    /// # Safety
+    /// See [`DmaBuffer::new`]: the `'static` lifetime rejects simple references to locals but
+    /// does not by itself guarantee the memory lives in a `static` variable.
+    pub fn from_static_mut(buffer: &'static mut [T]) -> Self {
+        let () = Self::LEN_FITS;
+        assert_eq!(buffer.len(), LEN * 2, "Buffer length must be exactly 2 * LEN");
+        let addr = buffer.as_ptr() as usize;
+        // `LEN_FITS` only proves the backing could fit somewhere in the region; the buffer's
+        // actual address is wherever the linker placed it, so the full range still needs
+        // checking at runtime.
+        assert!(
+            addr >= Region::START_ADDR
+                && addr + LEN * 2 * core::mem::size_of::<T>() <= Region::END_ADDR,
+            "Buffer not in DMA-accessible region"
+        );
+        Self {
+            ptr: NonNull::from(buffer).cast(),
+            cpu_half: ActiveHalf::First,
+            _region: PhantomData,
+        }
+    }
+
+    /// Returns the `(ptr, len)` pair for the whole `2 * LEN` backing, as needed by embassy's
+    /// circular DMA transfer APIs (the DMA controller owns the full backing continuously and
+    /// wraps around it; only half-by-half CPU access is arbitrated by this type).
+    ///
+    /// # Safety
+    /// This pointer is guaranteed to point to a valid DMA-accessible memory region, but the
+    /// caller must ensure proper synchronization: the CPU must only touch the half currently
+    /// returned by [`DmaRingBuffer::cpu_half`]/[`DmaRingBuffer::cpu_half_mut`].
+    pub fn dma_ptr_len(&mut self) -> (*mut T, usize) {
+        (self.ptr.as_ptr(), LEN * 2)
+    }
+
+    /// Returns the half currently owned by the CPU.
+    pub fn cpu_half(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.half_ptr(self.cpu_half), LEN) }
+    }
+
+    /// Returns a mutable view of the half currently owned by the CPU.
+    ///
+    /// # Safety
+    /// The caller must ensure the DMA controller is not concurrently transferring into this
+    /// half (it shouldn't be, since ownership tracking hands out each half exclusively).
+    pub fn cpu_half_mut(&mut self) -> &mut [T] {
+        let ptr = self.half_ptr(self.cpu_half);
+        unsafe { core::slice::from_raw_parts_mut(ptr, LEN) }
+    }
+
+    /// Flips which half is CPU-owned, handing the half the DMA just finished filling back to the
+    /// CPU. Call this from the half-transfer-complete / transfer-complete interrupt handler.
+    ///
+    /// For cacheable regions, this invalidates the D-cache over only the half being handed back,
+    /// so the CPU observes the data the DMA controller just wrote rather than stale cache lines.
+    ///
+    /// # Panics
+    /// In debug builds, panics if a half is not 32-byte cache-line aligned (see
+    /// [`DmaBuffer::finish_dma_write`] for why this is required).
+    pub fn commit_and_swap(&mut self) {
+        self.cpu_half = self.cpu_half.swap();
+
+        if Region::CACHEABLE {
+            let ptr = self.half_ptr(self.cpu_half);
+            assert_cache_line_aligned(ptr as usize, LEN * core::mem::size_of::<T>());
+            // SAFETY: this half just transitioned from DMA-owned to CPU-owned, so there are no
+            // outstanding CPU references to it yet; discarding its stale cache lines is sound.
+            let slice = unsafe { core::slice::from_raw_parts_mut(ptr, LEN) };
+            unsafe {
+                cortex_m::Peripherals::steal()
+                    .SCB
+                    .invalidate_dcache_by_slice(slice);
+            }
+        }
+    }
+
+    fn half_ptr(&self, half: ActiveHalf) -> *mut T {
+        let offset = match half {
+            ActiveHalf::First => 0,
+            ActiveHalf::Second => LEN,
+        };
+        // SAFETY: `offset` is at most LEN, and the backing holds 2 * LEN elements.
+        unsafe { self.ptr.as_ptr().add(offset) }
+    }
+}
+
+/// Implements `embedded-dma`'s `ReadBuffer`/`WriteBuffer` so `DmaBuffer` can be handed to any
+/// HAL that speaks those traits (stm32l4xx-hal, stm32f3xx-hal, stm32f1xx-hal, ...) instead of
+/// only embassy's own DMA APIs.
+///
+/// # Safety
+/// `DmaBuffer::new` already validated that the pointer falls within `Region`'s DMA-accessible
+/// address range, and `LEN` matches the backing allocation, so the returned `(ptr, len)` pair
+/// upholds the safety contract these traits require.
+#[cfg(feature = "embedded-dma")]
+unsafe impl<T: Copy, const LEN: usize, Region: DmaAccessible> embedded_dma::ReadBuffer
+    for DmaBuffer<T, LEN, Region>
+{
+    type Word = T;
+
+    unsafe fn read_buffer(&self) -> (*const T, usize) {
+        (self.ptr.as_ptr(), LEN)
+    }
+}
+
+/// # Safety
+/// See the `ReadBuffer` impl above; the same validated pointer/length pair is returned.
+#[cfg(feature = "embedded-dma")]
+unsafe impl<T: Copy, const LEN: usize, Region: DmaAccessible> embedded_dma::WriteBuffer
+    for DmaBuffer<T, LEN, Region>
+{
+    type Word = T;
+
+    unsafe fn write_buffer(&mut self) -> (*mut T, usize) {
+        (self.ptr.as_ptr(), LEN)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use grounded::uninit::GroundedArrayCell;
 
-    use crate::{DmaBuffer, Sram1};
+    use crate::{DmaAccessible, DmaBuffer, DmaController, DmaRingBuffer, Sram1};
+
+    /// A fake region spanning the whole address space, so any real `'static` address in a host
+    /// test passes the region-bounds check. Used by tests that exercise behavior other than the
+    /// address-range validation itself. `CACHEABLE` is `false` so cache-maintenance tests don't
+    /// try to touch real SCB cache-maintenance registers, which don't exist on a host target.
+    struct TestRegion;
+    impl DmaAccessible for TestRegion {
+        const START_ADDR: usize = 0;
+        const END_ADDR: usize = usize::MAX;
+        const CACHEABLE: bool = false;
+        const REACHABLE_BY: DmaController = DmaController::MainDma;
+    }
+
+    /// A region too small for any real `'static` buffer to fit in, used to exercise the
+    /// upper-bound (`addr + len <= END_ADDR`) half of the range check with `START_ADDR` at 0 (so
+    /// the lower-bound check alone would incorrectly pass).
+    struct TinyRegion;
+    impl DmaAccessible for TinyRegion {
+        const START_ADDR: usize = 0;
+        const END_ADDR: usize = 16;
+        const CACHEABLE: bool = false;
+        const REACHABLE_BY: DmaController = DmaController::MainDma;
+    }
 
     // Since there's no way to link to specific memory regions in a std environment,
     // the test is expected to panic, and I wanted to check if it builds and if it
@@ -183,4 +569,147 @@ mod tests {
         static BUFFER: GroundedArrayCell<u8, 128> = GroundedArrayCell::uninit();
         let _da = DmaBuffer::<u8, 128, Sram1>::new(&BUFFER, 0);
     }
+
+    #[test]
+    fn from_static_mut_accepts_any_static_mut_slice() {
+        static BUFFER: GroundedArrayCell<u8, 64> = GroundedArrayCell::uninit();
+        let slice: &'static mut [u8] = unsafe {
+            BUFFER.initialize_all_copied(0);
+            let (ptr, len) = BUFFER.get_ptr_len();
+            core::slice::from_raw_parts_mut(ptr, len)
+        };
+        let buf = DmaBuffer::<u8, 64, TestRegion>::from_static_mut(slice);
+        assert_eq!(buf.as_slice().len(), 64);
+    }
+
+    #[test]
+    fn from_static_mut_accepts_multi_byte_element_type() {
+        // Every other test in this file uses `T = u8`, where `size_of::<T>() == 1` makes the
+        // `LEN * size_of::<T>()` multiplication in `LEN_FITS`/the runtime check a no-op. This
+        // only checks that a `u32` buffer is accepted; `TestRegion` spans the whole address space,
+        // so it passes with or without that multiplication. Rejection of a multi-byte buffer
+        // that overruns its region is covered by the `compile_fail` doctest on `from_static_mut`.
+        static BUFFER: GroundedArrayCell<u32, 16> = GroundedArrayCell::uninit();
+        let slice: &'static mut [u32] = unsafe {
+            BUFFER.initialize_all_copied(0);
+            let (ptr, len) = BUFFER.get_ptr_len();
+            core::slice::from_raw_parts_mut(ptr, len)
+        };
+        let buf = DmaBuffer::<u32, 16, TestRegion>::from_static_mut(slice);
+        assert_eq!(buf.as_slice().len(), 16);
+    }
+
+    #[test]
+    fn cache_maintenance_is_a_no_op_for_non_cacheable_regions() {
+        // `TestRegion::CACHEABLE` is `false`, so both methods must skip their `if Region::CACHEABLE`
+        // block and never reach the `SCB` cache-maintenance calls, which would panic/fault on a host
+        // target where no such peripheral exists.
+        static BUFFER: GroundedArrayCell<u8, 64> = GroundedArrayCell::uninit();
+        let slice: &'static mut [u8] = unsafe {
+            BUFFER.initialize_all_copied(0);
+            let (ptr, len) = BUFFER.get_ptr_len();
+            core::slice::from_raw_parts_mut(ptr, len)
+        };
+        let mut buf = DmaBuffer::<u8, 64, TestRegion>::from_static_mut(slice);
+
+        buf.prepare_for_dma_read();
+        buf.finish_dma_write();
+    }
+
+    #[should_panic(expected = "Buffer not in DMA-accessible region")]
+    #[test]
+    fn from_static_mut_rejects_buffer_that_overruns_region_end() {
+        static BUFFER: GroundedArrayCell<u8, 8> = GroundedArrayCell::uninit();
+        let slice: &'static mut [u8] = unsafe {
+            BUFFER.initialize_all_copied(0);
+            let (ptr, len) = BUFFER.get_ptr_len();
+            core::slice::from_raw_parts_mut(ptr, len)
+        };
+        // START_ADDR is 0, so an `addr >= START_ADDR`-only check would pass; the upper-bound
+        // check must still reject this, since any real address is far past TinyRegion's 16-byte
+        // END_ADDR.
+        let _ = DmaBuffer::<u8, 8, TinyRegion>::from_static_mut(slice);
+    }
+
+    #[test]
+    fn ring_buffer_swaps_half_ownership() {
+        static BUFFER: GroundedArrayCell<u8, 16> = GroundedArrayCell::uninit();
+        let slice: &'static mut [u8] = unsafe {
+            BUFFER.initialize_all_copied(0);
+            let (ptr, len) = BUFFER.get_ptr_len();
+            core::slice::from_raw_parts_mut(ptr, len)
+        };
+        let mut ring = DmaRingBuffer::<u8, 8, TestRegion>::from_static_mut(slice);
+
+        ring.cpu_half_mut().fill(1);
+        assert_eq!(ring.cpu_half(), [1u8; 8]);
+
+        // The other half was never written, so after swapping to it we should see the original
+        // zero-initialized contents, not the CPU half we just filled.
+        ring.commit_and_swap();
+        assert_eq!(ring.cpu_half(), [0u8; 8]);
+
+        // Swapping back returns to the half we filled earlier, proving the half-pointer
+        // arithmetic consistently maps `ActiveHalf::First`/`Second` to the same underlying bytes.
+        ring.commit_and_swap();
+        assert_eq!(ring.cpu_half(), [1u8; 8]);
+    }
+
+    #[test]
+    fn ring_buffer_dma_ptr_len_covers_both_halves() {
+        static BUFFER: GroundedArrayCell<u8, 16> = GroundedArrayCell::uninit();
+        let slice: &'static mut [u8] = unsafe {
+            BUFFER.initialize_all_copied(0);
+            let (ptr, len) = BUFFER.get_ptr_len();
+            core::slice::from_raw_parts_mut(ptr, len)
+        };
+        let mut ring = DmaRingBuffer::<u8, 8, TestRegion>::from_static_mut(slice);
+
+        let (ptr, len) = ring.dma_ptr_len();
+        assert_eq!(len, 16);
+        assert_eq!(ptr as usize, ring.cpu_half().as_ptr() as usize);
+    }
+
+    // `CACHEABLE` is an associated const, so clippy sees these as trivially-constant assertions;
+    // they're still worth having as a regression guard if someone edits the wrong impl block.
+    #[allow(clippy::assertions_on_constants)]
+    #[test]
+    fn d3_domain_regions_are_bdma_only_and_noncacheable() {
+        assert_eq!(crate::Sram4::REACHABLE_BY, DmaController::Bdma);
+        assert!(!crate::Sram4::CACHEABLE);
+        assert_eq!(crate::BackupSram::REACHABLE_BY, DmaController::Bdma);
+        assert!(!crate::BackupSram::CACHEABLE);
+    }
+
+    #[allow(clippy::assertions_on_constants)]
+    #[test]
+    fn d2_domain_regions_are_main_dma_and_cacheable() {
+        assert_eq!(crate::Sram2::REACHABLE_BY, DmaController::MainDma);
+        assert!(crate::Sram2::CACHEABLE);
+        assert_eq!(crate::Sram3::REACHABLE_BY, DmaController::MainDma);
+        assert!(crate::Sram3::CACHEABLE);
+    }
+
+    #[cfg(feature = "embedded-dma")]
+    #[test]
+    fn embedded_dma_read_write_buffer_match_ptr_and_length() {
+        use embedded_dma::{ReadBuffer, WriteBuffer};
+
+        static BUFFER: GroundedArrayCell<u8, 32> = GroundedArrayCell::uninit();
+        let slice: &'static mut [u8] = unsafe {
+            BUFFER.initialize_all_copied(0);
+            let (ptr, len) = BUFFER.get_ptr_len();
+            core::slice::from_raw_parts_mut(ptr, len)
+        };
+        let mut buf = DmaBuffer::<u8, 32, TestRegion>::from_static_mut(slice);
+        let expected_ptr = buf.as_ptr();
+
+        let (read_ptr, read_len) = unsafe { buf.read_buffer() };
+        assert_eq!(read_ptr, expected_ptr);
+        assert_eq!(read_len, DmaBuffer::<u8, 32, TestRegion>::LENGTH);
+
+        let (write_ptr, write_len) = unsafe { buf.write_buffer() };
+        assert_eq!(write_ptr, expected_ptr as *mut u8);
+        assert_eq!(write_len, DmaBuffer::<u8, 32, TestRegion>::LENGTH);
+    }
 }